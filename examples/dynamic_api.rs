@@ -2,8 +2,8 @@
 //! to `kubectl get all --all-namespaces`.
 
 use kube::{
-    api::{Api, DynamicObject, ResourceExt},
-    client::{Discovery, Scope},
+    api::{Api, ApiResource, DynamicObject},
+    client::{discovery, discovery::ApiResourceExtras, Scope},
     Client,
 };
 use log::{info, warn};
@@ -19,37 +19,73 @@ async fn main() -> anyhow::Result<()> {
 
     let ns_filter = std::env::var("NAMESPACE").ok();
 
-    let discovery = Discovery::new(&client).await?;
-
-    for group in discovery.groups() {
-        let ver = group.preferred_version_or_guess();
-        for (res, extras) in group.resources_by_version(ver) {
-            let api: Api<DynamicObject> = if let Scope::Namespaced = extras.scope {
-                if let Some(ns) = &ns_filter {
-                    Api::namespaced_with(client.clone(), ns, &res)
-                } else {
-                    Api::all_with(client.clone(), &res)
-                }
+    // GROUP=apps,batch only talks to those groups, via a targeted, oneshot lookup. Otherwise fall
+    // back to a full walk of every group the apiserver advertises. Either way the result is the
+    // same `Vec<ApiGroup>`, so the rendering loop below doesn't need to care which path was taken.
+    let groups = match std::env::var("GROUP") {
+        Ok(csv) => {
+            let names = csv.split(',').collect::<Vec<_>>();
+            discovery::pinned(&client, &names).await?
+        }
+        Err(_) => discovery::all(&client).await?,
+    };
+
+    for group in &groups {
+        let ver = group.preferred_version_or_latest();
+        print_group(&client, &ns_filter, group.name(), ver, group.versioned_resources(ver)).await?;
+    }
+
+    Ok(())
+}
+
+/// Lists every discovered resource of one group/version as a server-side rendered table.
+async fn print_group(
+    client: &Client,
+    ns_filter: &Option<String>,
+    group: &str,
+    ver: &str,
+    resources: Vec<(ApiResource, ApiResourceExtras)>,
+) -> anyhow::Result<()> {
+    for (res, extras) in resources {
+        let api: Api<DynamicObject> = if let Scope::Namespaced = extras.scope {
+            if let Some(ns) = ns_filter {
+                Api::namespaced_with(client.clone(), ns, &res)
             } else {
                 Api::all_with(client.clone(), &res)
-            };
-
-            info!("{}/{} : {}", group.name(), ver, res.kind);
-
-            let list = match api.list(&Default::default()).await {
-                Ok(l) => l,
-                Err(e) => {
-                    warn!("Failed to list: {:#}", e);
-                    continue;
-                }
-            };
-            for item in list.items {
-                let name = item.name();
-                let ns = item.metadata.namespace.map(|s| s + "/").unwrap_or_default();
-                info!("\t\t{}{}", ns, name);
             }
+        } else {
+            Api::all_with(client.clone(), &res)
+        };
+
+        info!("{}/{} : {}", group, ver, res.kind);
+
+        // Ask the apiserver to render the same columns `kubectl get` would, instead of just
+        // printing namespace/name.
+        let table = match api.list_table(&Default::default()).await {
+            Ok(t) => t,
+            Err(e) => {
+                warn!("Failed to list: {:#}", e);
+                continue;
+            }
+        };
+        let headers = table
+            .column_definitions
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>()
+            .join("\t");
+        info!("\t\t{}", headers);
+        for row in table.rows {
+            let cells = row
+                .cells
+                .iter()
+                // Render strings as-is (no surrounding quotes); anything else falls back to its
+                // JSON representation.
+                .map(|c| c.as_str().map(str::to_string).unwrap_or_else(|| c.to_string()))
+                .collect::<Vec<_>>()
+                .join("\t");
+            info!("\t\t{}", cells);
         }
     }
-
     Ok(())
 }
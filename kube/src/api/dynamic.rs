@@ -1,6 +1,10 @@
-use crate::api::{metadata::TypeMeta, GroupVersionKind, Resource};
+use crate::{
+    api::{metadata::TypeMeta, Api, GroupVersionKind, Resource},
+    Error, Result,
+};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{APIResource, ObjectMeta};
-use std::borrow::Cow;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{borrow::Cow, fmt::Debug};
 
 /// Resource scope
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -49,6 +53,55 @@ impl Operations {
             other: Vec::new(),
         }
     }
+
+    /// Returns whether this resource supports `operation`, e.g. `"watch"` or `"deletecollection"`.
+    ///
+    /// Checks both the typed fields and [`Operations::other`], so a verb the apiserver advertises
+    /// that isn't one of the well-known ones is still correctly reported as supported.
+    pub fn supports_operation(&self, operation: &str) -> bool {
+        operation_supported(
+            operation,
+            self.create,
+            self.get,
+            self.list,
+            self.watch,
+            self.delete,
+            self.delete_collection,
+            self.update,
+            self.patch,
+            &self.other,
+        )
+    }
+}
+
+/// Shared verb-lookup behind `Operations::supports_operation`.
+///
+/// `dynamic::Operations` and `discovery::resource_details::Operations` are separate types with
+/// identical shapes (the latter predates this module and hasn't been unified with it yet), so
+/// this is factored out once rather than duplicated between their two `supports_operation` impls.
+pub(crate) fn operation_supported(
+    operation: &str,
+    create: bool,
+    get: bool,
+    list: bool,
+    watch: bool,
+    delete: bool,
+    delete_collection: bool,
+    update: bool,
+    patch: bool,
+    other: &[String],
+) -> bool {
+    match operation {
+        "create" => create,
+        "get" => get,
+        "list" => list,
+        "watch" => watch,
+        "delete" => delete,
+        "deletecollection" => delete_collection,
+        "update" => update,
+        "patch" => patch,
+        other_verb => other.iter().any(|o| o == other_verb),
+    }
 }
 
 /// Contains information about Kubernetes API resources
@@ -180,6 +233,51 @@ impl ApiResource {
             },
         }
     }
+
+    /// Creates an `ApiResource` by type-erasing a statically known `Resource`
+    ///
+    /// Unlike [`ApiResource::from_gvk`], this does not have to guess at any
+    /// fields: `group`, `version`, `api_version`, `kind` and `plural_name` are
+    /// all read directly off `K`'s [`Resource`] implementation, so they are
+    /// always correct for `K`. This is useful when `DynamicObject` or other
+    /// dynamic machinery needs to be mixed with a statically known type, such
+    /// as a type from `k8s-openapi` or one generated by `#[derive(CustomResource)]`.
+    ///
+    /// `scope`, `subresources` and `operations` cannot be derived from the
+    /// `Resource` trait alone, so they fall back to the same defaults that
+    /// [`ApiResource::from_gvk`] uses.
+    ///
+    /// ### Example usage:
+    /// ```
+    /// use kube::api::ApiResource;
+    /// use k8s_openapi::api::core::v1::Pod;
+    ///
+    /// let ar = ApiResource::erase::<Pod>(&());
+    /// assert_eq!(ar.kind, "Pod");
+    /// assert_eq!(ar.plural_name, "pods");
+    /// ```
+    pub fn erase<K: Resource>(dt: &K::DynamicType) -> Self {
+        ApiResource {
+            group: K::group(dt).to_string(),
+            version: K::version(dt).to_string(),
+            api_version: K::api_version(dt).to_string(),
+            kind: K::kind(dt).to_string(),
+            plural_name: K::plural(dt).to_string(),
+            scope: Scope::Namespaced,
+            subresources: vec!["status".to_string()],
+            operations: Operations {
+                create: true,
+                get: true,
+                list: true,
+                watch: true,
+                delete: true,
+                delete_collection: true,
+                update: true,
+                patch: true,
+                other: Vec::new(),
+            },
+        }
+    }
 }
 
 /// A dynamic representation of a kubernetes object
@@ -225,6 +323,55 @@ impl DynamicObject {
         self.metadata.namespace = Some(ns.into());
         self
     }
+
+    /// Attempts to convert this `DynamicObject` into a concrete `K`
+    ///
+    /// This bridges a dynamically discovered object (e.g. one obtained via the `discovery`
+    /// module) back into a statically known type, for the case where a matching compiled type
+    /// exists and the rest of a controller would rather work with it directly.
+    pub fn try_parse<K: Resource + DeserializeOwned>(self) -> Result<K> {
+        let DynamicObject { types, metadata, data } = self;
+        let mut value = data;
+        if let serde_json::Value::Object(obj) = &mut value {
+            if let Some(types) = types {
+                obj.insert("apiVersion".to_string(), types.api_version.into());
+                obj.insert("kind".to_string(), types.kind.into());
+            }
+            let metadata = serde_json::to_value(metadata).map_err(Error::SerdeError)?;
+            obj.insert("metadata".to_string(), metadata);
+        }
+        serde_json::from_value(value).map_err(Error::SerdeError)
+    }
+
+    /// Converts a typed resource into a `DynamicObject`
+    ///
+    /// This is the reverse of [`DynamicObject::try_parse`]: it lets code that discovers kinds
+    /// generically hand a statically typed `K` to the dynamic/`Api<DynamicObject>` world, e.g. to
+    /// send it to an apiserver endpoint whose `ApiResource` was only known at runtime.
+    ///
+    /// # Panics
+    /// Panics if `obj` does not serialize to a JSON object, which should not happen for any type
+    /// implementing [`Resource`].
+    pub fn from_typed<K: Resource + Serialize>(obj: &K, resource: &ApiResource) -> Self {
+        let mut value = serde_json::to_value(obj).expect("Resource always serializes to a JSON object");
+        let map = value
+            .as_object_mut()
+            .expect("Resource always serializes to a JSON object");
+        map.remove("apiVersion");
+        map.remove("kind");
+        let metadata = map
+            .remove("metadata")
+            .map(|m| serde_json::from_value(m).expect("Resource metadata is always an ObjectMeta"))
+            .unwrap_or_default();
+        DynamicObject {
+            types: Some(TypeMeta {
+                api_version: resource.api_version.clone(),
+                kind: resource.kind.clone(),
+            }),
+            metadata,
+            data: value,
+        }
+    }
 }
 
 impl Resource for DynamicObject {
@@ -259,6 +406,22 @@ impl Resource for DynamicObject {
     }
 }
 
+impl<K: Resource + Clone + DeserializeOwned + Debug> Api<K> {
+    /// Fetches a single object, returning `None` if it does not exist.
+    ///
+    /// This mirrors [`Api::get`](crate::Api::get), but turns the 404 the apiserver returns for a
+    /// missing object into `Ok(None)` instead of `Err`, so discovery/dynamic code paths that have
+    /// already checked [`Operations::supports_operation`] for `"get"` don't have to pattern-match
+    /// on the error just to handle "not found".
+    pub async fn get_opt(&self, name: &str) -> Result<Option<K>> {
+        match self.get(name).await {
+            Ok(obj) => Ok(Some(obj)),
+            Err(Error::Api(ae)) if ae.code == 404 => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -318,4 +481,26 @@ mod test {
         // make sure they return the same url_path through their impls
         assert_eq!(a1.request.url_path, a2.request.url_path);
     }
+
+    #[test]
+    fn convert_typed_resource_roundtrip() {
+        use k8s_openapi::api::core::v1::ConfigMap;
+
+        let mut cm = ConfigMap::default();
+        cm.metadata.name = Some("my-cm".to_string());
+        cm.metadata.namespace = Some("myns".to_string());
+        cm.data = Some([("foo".to_string(), "bar".to_string())].into());
+
+        let ar = ApiResource::erase::<ConfigMap>(&());
+        let obj = DynamicObject::from_typed(&cm, &ar);
+        assert_eq!(obj.metadata.name.as_deref(), Some("my-cm"));
+        assert_eq!(
+            obj.types.as_ref().map(|t| t.kind.as_str()),
+            Some("ConfigMap")
+        );
+
+        let cm2: ConfigMap = obj.try_parse().unwrap();
+        assert_eq!(cm2.metadata.name, cm.metadata.name);
+        assert_eq!(cm2.data, cm.data);
+    }
 }
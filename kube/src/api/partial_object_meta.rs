@@ -0,0 +1,130 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{metadata::TypeMeta, Api, ListParams, ObjectList, Resource, WatchEvent},
+    Result,
+};
+
+const PARTIAL_OBJECT_META_ACCEPT: &str = "application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1";
+const PARTIAL_OBJECT_META_LIST_ACCEPT: &str =
+    "application/json;as=PartialObjectMetadataList;g=meta.k8s.io;v=v1";
+
+/// A stripped-down representation of `K` carrying only `TypeMeta` and `ObjectMeta`.
+///
+/// This is what the apiserver returns when a request sends
+/// `Accept: application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1` (or the `...List`
+/// variant for list/watch requests). It is enough to track label, annotation and owner-reference
+/// changes across many kinds at a fraction of the bandwidth and decoding cost of the full object
+/// -- useful for a controller that discovers kinds at runtime via [`DynamicObject`](crate::api::DynamicObject)
+/// and only needs to react to metadata, not spec/status.
+///
+/// Obtained via [`Api::get_metadata`], [`Api::list_metadata`] or [`Api::watch_metadata`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartialObjectMeta<K> {
+    /// The type fields, not always present
+    #[serde(flatten, default)]
+    pub types: Option<TypeMeta>,
+    /// Object metadata
+    pub metadata: ObjectMeta,
+
+    #[serde(skip)]
+    _phantom: PhantomData<fn() -> K>,
+}
+
+impl<K: Resource> Resource for PartialObjectMeta<K> {
+    type DynamicType = K::DynamicType;
+
+    fn group(dt: &Self::DynamicType) -> Cow<'_, str> {
+        K::group(dt)
+    }
+
+    fn version(dt: &Self::DynamicType) -> Cow<'_, str> {
+        K::version(dt)
+    }
+
+    fn kind(dt: &Self::DynamicType) -> Cow<'_, str> {
+        K::kind(dt)
+    }
+
+    fn api_version(dt: &Self::DynamicType) -> Cow<'_, str> {
+        K::api_version(dt)
+    }
+
+    fn plural(dt: &Self::DynamicType) -> Cow<'_, str> {
+        K::plural(dt)
+    }
+
+    fn meta(&self) -> &ObjectMeta {
+        &self.metadata
+    }
+
+    fn meta_mut(&mut self) -> &mut ObjectMeta {
+        &mut self.metadata
+    }
+}
+
+impl<K: Resource> Api<K> {
+    /// Fetches a single object as metadata only ([`PartialObjectMeta`]), skipping spec/status
+    /// entirely.
+    pub async fn get_metadata(&self, name: &str) -> Result<PartialObjectMeta<K>> {
+        let mut req = self.request.get(name)?;
+        req.headers_mut()
+            .insert(http::header::ACCEPT, PARTIAL_OBJECT_META_ACCEPT.parse().unwrap());
+        self.client.request(req).await
+    }
+
+    /// Lists this resource as metadata only ([`PartialObjectMeta`]), skipping spec/status
+    /// entirely.
+    pub async fn list_metadata(&self, lp: &ListParams) -> Result<ObjectList<PartialObjectMeta<K>>> {
+        let mut req = self.request.list(lp)?;
+        req.headers_mut()
+            .insert(http::header::ACCEPT, PARTIAL_OBJECT_META_LIST_ACCEPT.parse().unwrap());
+        self.client.request(req).await
+    }
+
+    /// Watches this resource from `version`, yielding metadata-only ([`PartialObjectMeta`])
+    /// events.
+    ///
+    /// This is the metadata-only counterpart to `Api::watch`: a single watch request. No
+    /// metadata-only watcher is built on it in `kube_runtime`.
+    pub async fn watch_metadata(
+        &self,
+        lp: &ListParams,
+        version: &str,
+    ) -> Result<impl futures::Stream<Item = Result<WatchEvent<PartialObjectMeta<K>>>>> {
+        let mut req = self.request.watch(lp, version)?;
+        req.headers_mut()
+            .insert(http::header::ACCEPT, PARTIAL_OBJECT_META_LIST_ACCEPT.parse().unwrap());
+        self.client.request_events(req).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PartialObjectMeta;
+    use k8s_openapi::api::core::v1::ConfigMap;
+
+    #[test]
+    fn roundtrip_ignores_data_and_preserves_type_meta() {
+        let json = serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": { "name": "my-cm", "namespace": "myns" },
+            "data": { "foo": "bar" },
+        });
+
+        let meta: PartialObjectMeta<ConfigMap> = serde_json::from_value(json).unwrap();
+        assert_eq!(meta.metadata.name.as_deref(), Some("my-cm"));
+        assert_eq!(meta.types.as_ref().map(|t| t.kind.as_str()), Some("ConfigMap"));
+
+        // serializing back should carry TypeMeta and ObjectMeta, and nothing else -- the whole
+        // point of PartialObjectMeta is that spec/status never round-trip through it.
+        let value = serde_json::to_value(&meta).unwrap();
+        assert_eq!(value["apiVersion"], "v1");
+        assert_eq!(value["metadata"]["name"], "my-cm");
+        assert!(value.get("data").is_none());
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIGroup;
+
+use crate::{
+    api::ApiResource,
+    client::discovery::resource_details::ApiResourceExtras,
+    Client, Result,
+};
+
+/// Core group name, as used when talking to the `Client`'s group-discovery endpoints.
+const CORE_GROUP: &str = "";
+/// The only version the legacy/core group (`""`) is ever served under.
+const CORE_GROUP_VERSION: &str = "v1";
+
+/// A discovered API group, holding every version the apiserver advertises for it.
+///
+/// This is the one representation the `discovery` module hands back, whether a caller asks for
+/// a single group via [`group`], a fixed set via [`pinned`], or every group the apiserver has via
+/// [`all`] -- there's no separate "full discovery" type with its own naming for the same data.
+/// Prefer [`group`]/[`pinned`] over [`all`] on resource-heavy clusters (hundreds of CRDs), since
+/// they only talk to the groups actually needed instead of walking the entire API surface.
+#[derive(Debug, Clone)]
+pub struct ApiGroup {
+    name: String,
+    preferred_version: Option<String>,
+    resources_by_version: HashMap<String, Vec<(ApiResource, ApiResourceExtras)>>,
+}
+
+impl ApiGroup {
+    pub(crate) fn new(
+        group: &APIGroup,
+        resources_by_version: HashMap<String, Vec<(ApiResource, ApiResourceExtras)>>,
+    ) -> Self {
+        ApiGroup {
+            name: group.name.clone(),
+            preferred_version: group.preferred_version.as_ref().map(|v| v.version.clone()),
+            resources_by_version,
+        }
+    }
+
+    pub(crate) fn core(resources: Vec<(ApiResource, ApiResourceExtras)>) -> Self {
+        let mut resources_by_version = HashMap::new();
+        resources_by_version.insert(CORE_GROUP_VERSION.to_string(), resources);
+        ApiGroup {
+            name: CORE_GROUP.to_string(),
+            preferred_version: Some(CORE_GROUP_VERSION.to_string()),
+            resources_by_version,
+        }
+    }
+
+    /// Returns the name of this group (empty string for the legacy core group).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns every version that is served for this group.
+    ///
+    /// There is no guaranteed ordering; use [`ApiGroup::preferred_version_or_latest`] to pick one.
+    pub fn versions(&self) -> impl Iterator<Item = &str> {
+        self.resources_by_version.keys().map(String::as_str)
+    }
+
+    /// Returns the version that the apiserver recommends using for this group.
+    ///
+    /// Falls back to the alphabetically latest version if the apiserver did not advertise a
+    /// preference (this happens for some aggregated/custom API groups).
+    pub fn preferred_version_or_latest(&self) -> &str {
+        match &self.preferred_version {
+            Some(v) => v.as_str(),
+            None => self
+                .versions()
+                .max()
+                .expect("ApiGroup always has at least one version"),
+        }
+    }
+
+    /// Returns the resources and their capabilities that are served under `version` of this group.
+    ///
+    /// Returns an empty `Vec` if `version` is not one of [`ApiGroup::versions`].
+    pub fn versioned_resources(&self, version: &str) -> Vec<(ApiResource, ApiResourceExtras)> {
+        self.resources_by_version.get(version).cloned().unwrap_or_default()
+    }
+
+    /// Returns the resources served under [`ApiGroup::preferred_version_or_latest`].
+    pub fn recommended_resources(&self) -> Vec<(ApiResource, ApiResourceExtras)> {
+        self.versioned_resources(self.preferred_version_or_latest())
+    }
+
+    /// Finds a resource by `kind` among [`ApiGroup::recommended_resources`].
+    pub fn recommended_kind(&self, kind: &str) -> Option<(ApiResource, ApiResourceExtras)> {
+        self.recommended_resources()
+            .into_iter()
+            .find(|(resource, _)| resource.kind == kind)
+    }
+}
+
+/// Discovers a single named API group, such as `"apps"` or `"batch"`, without enumerating the
+/// rest of the cluster's API surface.
+///
+/// This performs one request to find out which versions the group has (skipped for the core
+/// group, which is always `v1`), and one request per version to list its resources, rather than
+/// the full group-by-group walk that [`all`] does. Pass the empty string to discover the legacy
+/// core group (`Pod`, `Service`, ...).
+///
+/// ```no_run
+/// # async fn scope(client: kube::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// use kube::client::discovery;
+/// let apps = discovery::group(&client, "apps").await?;
+/// for (resource, _caps) in apps.recommended_resources() {
+///     println!("{}/{}: {}", apps.name(), apps.preferred_version_or_latest(), resource.kind);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn group(client: &Client, group: &str) -> Result<ApiGroup> {
+    if group == CORE_GROUP {
+        return fetch_core(client).await;
+    }
+
+    let api_group = client
+        .list_api_groups()
+        .await?
+        .groups
+        .into_iter()
+        .find(|g| g.name == group)
+        .ok_or_else(|| crate::Error::Discovery(format!("api group {} not found", group)))?;
+    fetch_group(client, &api_group).await
+}
+
+/// Discovers only the explicitly named groups, ignoring everything else the apiserver advertises.
+///
+/// A controller that already knows which groups it depends on can use this to discover exactly
+/// those and nothing else, rather than paying for [`all`]'s full walk.
+pub async fn pinned(client: &Client, groups: &[&str]) -> Result<Vec<ApiGroup>> {
+    let mut out = Vec::with_capacity(groups.len());
+    for g in groups {
+        out.push(group(client, g).await?);
+    }
+    Ok(out)
+}
+
+/// Discovers every API group the apiserver advertises, core group included.
+///
+/// This is the full, unfiltered walk: one request to enumerate the groups, then the same
+/// per-group fetch [`group`] does for each of them. Prefer [`group`] or [`pinned`] when only a
+/// known subset of groups is actually needed.
+pub async fn all(client: &Client) -> Result<Vec<ApiGroup>> {
+    let mut groups = vec![fetch_core(client).await?];
+    for api_group in client.list_api_groups().await?.groups {
+        groups.push(fetch_group(client, &api_group).await?);
+    }
+    Ok(groups)
+}
+
+async fn fetch_core(client: &Client) -> Result<ApiGroup> {
+    let list = client.list_api_group_resources(CORE_GROUP_VERSION).await?;
+    Ok(ApiGroup::core(top_level_resources(&list)))
+}
+
+async fn fetch_group(client: &Client, api_group: &APIGroup) -> Result<ApiGroup> {
+    let mut resources_by_version = HashMap::new();
+    for v in &api_group.versions {
+        let list = client.list_api_group_resources(&v.group_version).await?;
+        resources_by_version.insert(v.version.clone(), top_level_resources(&list));
+    }
+    Ok(ApiGroup::new(api_group, resources_by_version))
+}
+
+fn top_level_resources(
+    list: &k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResourceList,
+) -> Vec<(ApiResource, ApiResourceExtras)> {
+    list.resources
+        .iter()
+        .filter(|r| !r.name.contains('/')) // subresources are folded into their parent's extras
+        .map(|r| {
+            let resource = ApiResource::from_apiresource(r, &list.group_version);
+            let extras = ApiResourceExtras::from_apiresourcelist(list, &r.name);
+            (resource, extras)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        api::dynamic,
+        client::discovery::resource_details::{Operations, Scope},
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{APIGroup, GroupVersionForDiscovery};
+
+    fn fake_resource(kind: &str) -> (ApiResource, ApiResourceExtras) {
+        let resource = ApiResource {
+            group: "example.com".to_string(),
+            version: "v1".to_string(),
+            api_version: "example.com/v1".to_string(),
+            kind: kind.to_string(),
+            plural_name: kind.to_ascii_lowercase(),
+            scope: dynamic::Scope::Namespaced,
+            subresources: Vec::new(),
+            operations: dynamic::Operations::empty(),
+        };
+        let extras = ApiResourceExtras {
+            scope: Scope::Namespaced,
+            subresources: Vec::new(),
+            operations: Operations::empty(),
+        };
+        (resource, extras)
+    }
+
+    #[test]
+    fn preferred_version_falls_back_to_latest_when_unset() {
+        let api_group = APIGroup {
+            name: "example.com".to_string(),
+            versions: vec![
+                GroupVersionForDiscovery {
+                    group_version: "example.com/v1".to_string(),
+                    version: "v1".to_string(),
+                },
+                GroupVersionForDiscovery {
+                    group_version: "example.com/v2".to_string(),
+                    version: "v2".to_string(),
+                },
+            ],
+            preferred_version: None,
+            server_address_by_client_cidrs: None,
+        };
+        let mut resources_by_version = HashMap::new();
+        resources_by_version.insert("v1".to_string(), vec![fake_resource("Foo")]);
+        resources_by_version.insert("v2".to_string(), vec![fake_resource("Foo")]);
+        let group = ApiGroup::new(&api_group, resources_by_version);
+
+        assert_eq!(group.preferred_version_or_latest(), "v2");
+    }
+
+    #[test]
+    fn versioned_resources_is_empty_for_unknown_version() {
+        let group = ApiGroup::core(vec![fake_resource("Pod")]);
+
+        assert!(group.versioned_resources("v2").is_empty());
+        assert_eq!(group.versioned_resources("v1").len(), 1);
+    }
+
+    #[test]
+    fn recommended_kind_hit_and_miss() {
+        let group = ApiGroup::core(vec![fake_resource("Pod"), fake_resource("Service")]);
+
+        assert_eq!(group.recommended_kind("Pod").unwrap().0.kind, "Pod");
+        assert!(group.recommended_kind("Deployment").is_none());
+    }
+}
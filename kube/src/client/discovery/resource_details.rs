@@ -48,6 +48,25 @@ impl Operations {
             other: Vec::new(),
         }
     }
+
+    /// Returns whether this resource supports `operation`, e.g. `"watch"` or `"deletecollection"`.
+    ///
+    /// Checks both the typed fields and [`Operations::other`], so a verb the apiserver advertises
+    /// that isn't one of the well-known ones is still correctly reported as supported.
+    pub fn supports_operation(&self, operation: &str) -> bool {
+        crate::api::dynamic::operation_supported(
+            operation,
+            self.create,
+            self.get,
+            self.list,
+            self.watch,
+            self.delete,
+            self.delete_collection,
+            self.update,
+            self.patch,
+            &self.other,
+        )
+    }
 }
 /// Contains additional, detailed information abount API resource
 #[derive(Debug, Clone)]
@@ -98,7 +117,7 @@ impl ApiResourceExtras {
         for res in &list.resources {
             if let Some(subresource_name) = res.name.strip_prefix(&subresource_name_prefix) {
                 let mut api_resource = ApiResource::from_apiresource(res, &list.group_version);
-                api_resource.plural = subresource_name.to_string();
+                api_resource.plural_name = subresource_name.to_string();
                 let extra = ApiResourceExtras::from_apiresourcelist(list, &res.name);
                 subresources.push((api_resource, extra));
             }